@@ -0,0 +1,90 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A pluggable clock source, so consumers can mock "now" in tests.
+//!
+//! Gated behind the `clock` feature. When no clock has been installed with [`set_clock`],
+//! [`Local::now`](crate::Local::now) and [`Local::today`](crate::Local::today) fall back to the
+//! real OS clock exactly as before.
+
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::{DateTime, Error, TimeDelta, Utc};
+
+/// A source of the current time.
+///
+/// Implement this for a custom "now"; most consumers will reach for [`FixedClock`] instead of
+/// writing their own.
+pub trait Clock: Send + Sync {
+    /// The current date and time, in UTC.
+    fn now(&self) -> Result<DateTime<Utc>, Error>;
+}
+
+fn installed_clock() -> &'static RwLock<Option<Arc<dyn Clock>>> {
+    static CLOCK: OnceLock<RwLock<Option<Arc<dyn Clock>>>> = OnceLock::new();
+    CLOCK.get_or_init(|| RwLock::new(None))
+}
+
+/// Install a process-wide clock, consulted by [`Local::now`](crate::Local::now) and
+/// [`Local::today`](crate::Local::today) in place of the OS clock.
+///
+/// The override applies to the whole process, not just the calling thread, so tests that rely
+/// on it should not run concurrently with tests that expect the real OS clock (e.g. serialize
+/// them behind a `static Mutex`, or run them in a dedicated test binary).
+pub fn set_clock(clock: Arc<dyn Clock>) {
+    *installed_clock().write().unwrap_or_else(|e| e.into_inner()) = Some(clock);
+}
+
+/// Remove any clock installed by [`set_clock`], reverting to the OS clock.
+pub fn clear_clock() {
+    *installed_clock().write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Ask the installed clock, if any, for the current time.
+pub(crate) fn now() -> Option<Result<DateTime<Utc>, Error>> {
+    installed_clock().read().unwrap_or_else(|e| e.into_inner()).as_ref().map(|clock| clock.now())
+}
+
+/// A clock pinned to a chosen instant, for deterministic tests.
+///
+/// ```
+/// # #[cfg(feature = "clock")] {
+/// use std::sync::Arc;
+/// use chrono::clock::{set_clock, FixedClock};
+/// use chrono::{DateTime, Local, TimeDelta, Utc};
+///
+/// let epoch: DateTime<Utc> = "1970-01-01T00:00:00Z".parse().unwrap();
+/// let clock = Arc::new(FixedClock::new(epoch));
+/// set_clock(clock.clone());
+///
+/// assert_eq!(Local::now().unwrap().with_timezone(&Utc), epoch);
+/// clock.advance(TimeDelta::hours(1));
+/// assert_eq!(Local::now().unwrap().with_timezone(&Utc), epoch + TimeDelta::hours(1));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedClock(Arc<RwLock<DateTime<Utc>>>);
+
+impl FixedClock {
+    /// Create a clock frozen at `now`.
+    pub fn new(now: DateTime<Utc>) -> FixedClock {
+        FixedClock(Arc::new(RwLock::new(now)))
+    }
+
+    /// Pin the frozen instant to a new value.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+
+    /// Move the frozen instant forward (or backward, given a negative `delta`) in place.
+    pub fn advance(&self, delta: TimeDelta) {
+        let mut guard = self.0.write().unwrap_or_else(|e| e.into_inner());
+        *guard = *guard + delta;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Result<DateTime<Utc>, Error> {
+        Ok(*self.0.read().unwrap_or_else(|e| e.into_inner()))
+    }
+}
@@ -2,10 +2,14 @@
 use core::fmt;
 
 /// Error type for date and time operations.
-// TODO: Error sources that are not yet covered are the platform APIs, the parsing of a `TZfile` and
-// parsing of a `TZ` environment variable.
+///
+/// This no longer derives `Copy`: the `TzDataInvalid`/`TzStringInvalid`/`PlatformError` variants
+/// carry a reference-counted source error (see [`TzError`]) so the failure chain stays inspectable through
+/// [`std::error::Error::source`], and a shared, type-erased source can't be duplicated bitwise.
+/// `Eq`/`PartialEq` are kept: [`TzError`] compares by its rendered message, so existing
+/// `assert_eq!(result, Err(Error::X))`-style comparisons keep working.
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Error {
     /// One or more of the arguments to a function are invalid.
     ///
@@ -78,8 +82,68 @@ pub enum Error {
 
     /// There was an error on the formatting string, or there were non-supported formating items.
     BadFormat,
+
+    /// The system zoneinfo (TZif) data for a time zone was malformed, truncated, or could not be
+    /// read.
+    #[cfg(feature = "std")]
+    TzDataInvalid(TzError),
+
+    /// A `TZ` environment variable, or an equivalent platform time zone string, could not be
+    /// parsed.
+    #[cfg(feature = "std")]
+    TzStringInvalid(TzError),
+
+    /// An underlying platform time zone API call failed.
+    #[cfg(feature = "std")]
+    PlatformError(TzError),
+}
+
+/// The underlying cause of an [`Error::TzDataInvalid`], [`Error::TzStringInvalid`], or
+/// [`Error::PlatformError`].
+///
+/// Wraps the original error (an I/O error, a malformed-TZif description, ...) so it stays
+/// reachable through [`std::error::Error::source`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct TzError(std::sync::Arc<dyn std::error::Error + Send + Sync + 'static>);
+
+#[cfg(feature = "std")]
+impl TzError {
+    pub(crate) fn new<E>(err: E) -> TzError
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        TzError(std::sync::Arc::new(err))
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for TzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for TzError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
+
+// The wrapped `dyn Error` has no meaningful notion of equality, so compare by rendered message
+// (with a cheap `Arc::ptr_eq` fast path) instead. This is enough to keep `Error` itself
+// `Eq`/`PartialEq` for existing `assert_eq!`/`matches!` call sites.
+#[cfg(feature = "std")]
+impl PartialEq for TzError {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0) || self.0.to_string() == other.0.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for TzError {}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -105,12 +169,25 @@ impl fmt::Display for Error {
             Error::InputTooShort => write!(f, "premature end of input"),
             Error::InputTooLong => write!(f, "trailing input"),
             Error::BadFormat => write!(f, "bad or unsupported format string"),
+            #[cfg(feature = "std")]
+            Error::TzDataInvalid(e) => write!(f, "invalid time zone data: {}", e),
+            #[cfg(feature = "std")]
+            Error::TzStringInvalid(e) => write!(f, "invalid time zone string: {}", e),
+            #[cfg(feature = "std")]
+            Error::PlatformError(e) => write!(f, "platform time zone API call failed: {}", e),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::TzDataInvalid(e) | Error::TzStringInvalid(e) | Error::PlatformError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 pub(crate) const INVALID_PARAM: Error = Error::InvalidParameter;
 pub(crate) const INVALID_DATE: Error = Error::InvalidDate;
@@ -0,0 +1,222 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Parsing of human-readable relative date and time expressions, e.g. `"3 days ago"` or
+//! `"next friday"`, in the style of GNU `date -d`.
+
+use crate::{DateTime, Error, Months, TimeDelta, TimeZone, Weekday};
+
+/// Parse a human-readable relative date/time expression into a concrete [`DateTime`], anchored
+/// at `base`.
+///
+/// Recognizes the anchor words `now`/`today`, `yesterday`, `tomorrow`; `next`/`last <weekday>`;
+/// `next`/`last <unit>` as ±1 of that unit (e.g. `"next year"`, `"last month"`); and sequences of
+/// `(sign? integer) unit` pairs (`sec`, `min`, `hour`, `day`, `week`, `month`, `year`, plural and
+/// abbreviated forms), optionally followed by a trailing `ago` which negates the whole
+/// accumulated offset. Terms may be combined, e.g. `"2 days 4 hours ago"`.
+///
+/// `sec` through `week` are applied as a plain [`TimeDelta`] (a day is always 86,400 seconds);
+/// `month` and `year` are applied with
+/// [`Months`] so that e.g. `"1 month"` from January 31 lands on the last day of February rather
+/// than overflowing into March.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the input is empty or contains an unrecognized token, and
+/// propagates errors from the underlying date arithmetic (such as [`Error::DateOutOfRange`], or
+/// [`Error::InvalidDate`] if the result falls in a DST gap).
+pub fn parse_relative<Tz: TimeZone>(input: &str, base: DateTime<Tz>) -> Result<DateTime<Tz>, Error> {
+    let normalized = input.trim().to_lowercase();
+    if normalized.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+
+    match normalized.as_str() {
+        "now" | "today" => return Ok(base),
+        "yesterday" => return base.checked_sub_signed(TimeDelta::days(1)?).ok_or(Error::DateOutOfRange),
+        "tomorrow" => return base.checked_add_signed(TimeDelta::days(1)?).ok_or(Error::DateOutOfRange),
+        _ => {}
+    }
+
+    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+
+    if tokens.len() == 2 {
+        if let (direction @ ("next" | "last"), Ok(weekday)) = (tokens[0], tokens[1].parse::<Weekday>()) {
+            return Ok(nearest_weekday(&base, weekday, direction == "next"));
+        }
+
+        if let (direction @ ("next" | "last"), unit) = (tokens[0], tokens[1]) {
+            let sign: i64 = if direction == "next" { 1 } else { -1 };
+            if let Some(factor) = unit_seconds(unit) {
+                let seconds = sign.checked_mul(factor).ok_or(Error::DateOutOfRange)?;
+                return base.checked_add_signed(TimeDelta::seconds(seconds)?).ok_or(Error::DateOutOfRange);
+            } else if let Some(factor) = unit_months(unit) {
+                let months = sign.checked_mul(factor).ok_or(Error::DateOutOfRange)?;
+                return apply_months(&base, months);
+            }
+        }
+    }
+
+    let negate = tokens.last() == Some(&"ago");
+    let terms = if negate { &tokens[..tokens.len() - 1] } else { &tokens[..] };
+    if terms.is_empty() || terms.len() % 2 != 0 {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut seconds_total: i64 = 0;
+    let mut months_total: i64 = 0;
+    for pair in terms.chunks(2) {
+        let count: i64 = pair[0].parse().map_err(|_| Error::InvalidInput)?;
+        if let Some(factor) = unit_seconds(pair[1]) {
+            let amount = count.checked_mul(factor).ok_or(Error::DateOutOfRange)?;
+            seconds_total = seconds_total.checked_add(amount).ok_or(Error::DateOutOfRange)?;
+        } else if let Some(factor) = unit_months(pair[1]) {
+            let amount = count.checked_mul(factor).ok_or(Error::DateOutOfRange)?;
+            months_total = months_total.checked_add(amount).ok_or(Error::DateOutOfRange)?;
+        } else {
+            return Err(Error::InvalidInput);
+        }
+    }
+
+    if negate {
+        seconds_total = seconds_total.checked_neg().ok_or(Error::DateOutOfRange)?;
+        months_total = months_total.checked_neg().ok_or(Error::DateOutOfRange)?;
+    }
+
+    let after_months = apply_months(&base, months_total)?;
+    after_months.checked_add_signed(TimeDelta::seconds(seconds_total)?).ok_or(Error::DateOutOfRange)
+}
+
+/// Shift `base` by `months` calendar months, clamping the day-of-month if necessary.
+fn apply_months<Tz: TimeZone>(base: &DateTime<Tz>, months: i64) -> Result<DateTime<Tz>, Error> {
+    if months == 0 {
+        return Ok(base.clone());
+    }
+
+    let naive_date = base.date_naive();
+    let new_date = if months > 0 {
+        let months = u32::try_from(months).map_err(|_| Error::DateOutOfRange)?;
+        naive_date.checked_add_months(Months::new(months))
+    } else {
+        let months = u32::try_from(months.checked_neg().ok_or(Error::DateOutOfRange)?)
+            .map_err(|_| Error::DateOutOfRange)?;
+        naive_date.checked_sub_months(Months::new(months))
+    }
+    .ok_or(Error::DateOutOfRange)?;
+
+    base.timezone().from_local_datetime(&new_date.and_time(base.time()))?.single()
+}
+
+/// Find the nearest `weekday` strictly before (`forward = false`) or after (`forward = true`)
+/// `base`, so that e.g. `next monday` from a Monday resolves to the following week.
+fn nearest_weekday<Tz: TimeZone>(base: &DateTime<Tz>, weekday: Weekday, forward: bool) -> DateTime<Tz> {
+    let current = base.weekday().num_days_from_monday() as i64;
+    let target = weekday.num_days_from_monday() as i64;
+    let diff = if forward {
+        match (target - current).rem_euclid(7) {
+            0 => 7,
+            d => d,
+        }
+    } else {
+        match (current - target).rem_euclid(7) {
+            0 => 7,
+            d => d,
+        }
+    };
+
+    let offset = TimeDelta::days(diff).expect("diff is always within range");
+    if forward { base.clone() + offset } else { base.clone() - offset }
+}
+
+fn unit_seconds(unit: &str) -> Option<i64> {
+    match unit {
+        "sec" | "secs" | "second" | "seconds" => Some(1),
+        "min" | "mins" | "minute" | "minutes" => Some(60),
+        "hour" | "hours" => Some(3_600),
+        "day" | "days" => Some(86_400),
+        "week" | "weeks" => Some(86_400 * 7),
+        _ => None,
+    }
+}
+
+fn unit_months(unit: &str) -> Option<i64> {
+    match unit {
+        "month" | "months" => Some(1),
+        "year" | "years" => Some(12),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_relative;
+    use crate::{Datelike, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+
+    fn base() -> crate::DateTime<Utc> {
+        Utc.from_utc_datetime(&NaiveDate::from_ymd(2024, 1, 15).unwrap().and_hms(12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn anchors() {
+        assert_eq!(parse_relative("now", base()).unwrap(), base());
+        assert_eq!(parse_relative("today", base()).unwrap(), base());
+        assert_eq!(parse_relative("yesterday", base()).unwrap().date_naive().day(), 14);
+        assert_eq!(parse_relative("tomorrow", base()).unwrap().date_naive().day(), 16);
+    }
+
+    #[test]
+    fn simple_offsets() {
+        assert_eq!(parse_relative("3 days ago", base()).unwrap().date_naive().day(), 12);
+        assert_eq!(parse_relative("2 weeks", base()).unwrap().date_naive().day(), 29);
+    }
+
+    #[test]
+    fn combined_offsets() {
+        let dt = parse_relative("2 days 4 hours ago", base()).unwrap();
+        assert_eq!(dt.date_naive().day(), 13);
+        assert_eq!(dt.time().hour(), 8);
+    }
+
+    #[test]
+    fn month_clamping() {
+        let jan31 = Utc.from_utc_datetime(&NaiveDate::from_ymd(2024, 1, 31).unwrap().and_hms(0, 0, 0).unwrap());
+        let dt = parse_relative("1 month", jan31).unwrap();
+        assert_eq!(dt.date_naive().month(), 2);
+        assert_eq!(dt.date_naive().day(), 29);
+    }
+
+    #[test]
+    fn next_and_last_weekday() {
+        // 2024-01-15 is a Monday.
+        assert_eq!(base().weekday(), Weekday::Mon);
+        assert_eq!(parse_relative("next monday", base()).unwrap().date_naive().day(), 22);
+        assert_eq!(parse_relative("last friday", base()).unwrap().date_naive().day(), 12);
+    }
+
+    #[test]
+    fn next_and_last_unit() {
+        assert_eq!(parse_relative("next year", base()).unwrap().date_naive().year(), 2025);
+        assert_eq!(parse_relative("last month", base()).unwrap().date_naive().month(), 12);
+        assert_eq!(parse_relative("last month", base()).unwrap().date_naive().year(), 2023);
+        assert_eq!(parse_relative("next day", base()).unwrap().date_naive().day(), 16);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(parse_relative("", base()).is_err());
+        assert!(parse_relative("gibberish", base()).is_err());
+        assert!(parse_relative("3 fortnights", base()).is_err());
+    }
+
+    #[test]
+    fn huge_quantity_is_an_error_not_a_panic() {
+        assert!(matches!(
+            parse_relative("99999999999999999 days ago", base()),
+            Err(crate::Error::DateOutOfRange)
+        ));
+        assert!(matches!(
+            parse_relative("99999999999999999 months", base()),
+            Err(crate::Error::DateOutOfRange)
+        ));
+    }
+}
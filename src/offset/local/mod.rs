@@ -7,7 +7,7 @@
 use rkyv::{Archive, Deserialize, Serialize};
 
 use super::fixed::FixedOffset;
-use crate::naive::{NaiveDate, NaiveDateTime};
+use crate::naive::NaiveDateTime;
 use crate::offset::LocalResult;
 #[allow(deprecated)]
 use crate::Date;
@@ -36,7 +36,20 @@ mod inner;
 mod inner;
 
 #[cfg(unix)]
-mod tz_info;
+pub(crate) mod tz_info;
+
+/// Ask the process-wide clock installed via [`crate::clock::set_clock`], if any, for the current
+/// time, converted into the local time zone. Returns `None` when the `clock` feature is disabled
+/// or no clock has been installed, so that `Local::now` falls through to the OS clock.
+#[cfg(feature = "clock")]
+fn clock_override() -> Option<Result<DateTime<Local>, Error>> {
+    crate::clock::now().map(|result| result.map(|utc| utc.with_timezone(&Local)))
+}
+
+#[cfg(not(feature = "clock"))]
+fn clock_override() -> Option<Result<DateTime<Local>, Error>> {
+    None
+}
 
 /// The local timescale. This is implemented via the standard `time` crate.
 ///
@@ -72,6 +85,9 @@ impl Local {
         not(any(target_os = "emscripten", target_os = "wasi"))
     )))]
     pub fn now() -> Result<DateTime<Local>, Error> {
+        if let Some(result) = clock_override() {
+            return result;
+        }
         inner::now()
     }
 
@@ -83,6 +99,11 @@ impl Local {
     ))]
     pub fn now() -> Result<DateTime<Local>, Error> {
         use super::Utc;
+
+        if let Some(result) = clock_override() {
+            return result;
+        }
+
         let now: DateTime<Utc> = super::Utc::now()?;
 
         // Workaround missing timezone logic in `time` crate
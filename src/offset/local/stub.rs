@@ -0,0 +1,18 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Fallback local time zone lookup for targets with no known way to ask the platform for its
+//! zone (anything that isn't unix, Windows, or wasm32 with `wasmbind`). Treats local time as
+//! UTC, matching upstream chrono's behavior on these targets; there's no platform API call here
+//! that could fail, so no [`Error::PlatformError`] path applies.
+
+use crate::offset::LocalResult;
+use crate::{DateTime, Error, Local, NaiveDateTime};
+
+pub(super) fn now() -> Result<DateTime<Local>, Error> {
+    Ok(DateTime::from_utc(crate::Utc::now()?.naive_utc(), crate::offset::FixedOffset::east(0)?))
+}
+
+pub(super) fn naive_to_local(d: &NaiveDateTime, _local: bool) -> Result<LocalResult<DateTime<Local>>, Error> {
+    Ok(LocalResult::Single(DateTime::from_utc(*d, crate::offset::FixedOffset::east(0)?)))
+}
@@ -0,0 +1,423 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A minimal parser for the binary TZif format used by the IANA time zone database (RFC 8536),
+//! as found under `/usr/share/zoneinfo` on most unix systems.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::TzError;
+use crate::Error;
+
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+const TZIF_MAGIC: &[u8; 4] = b"TZif";
+
+/// A single local time type record: a UTC offset plus metadata.
+#[derive(Debug, Clone)]
+pub(crate) struct LocalTimeType {
+    pub(crate) utc_offset: i32,
+    pub(crate) is_dst: bool,
+}
+
+/// A malformed or truncated TZif file.
+#[derive(Debug)]
+struct TzifError(&'static str);
+
+impl std::fmt::Display for TzifError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "malformed TZif data: {}", self.0)
+    }
+}
+
+impl std::error::Error for TzifError {}
+
+/// A parsed TZif time zone: the UTC transition instants, in order, and the local time type that
+/// takes effect at each one.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeZoneData {
+    transitions: Vec<(i64, usize)>,
+    local_time_types: Vec<LocalTimeType>,
+}
+
+/// The result of resolving a local (naive) timestamp against a transition table: it may name a
+/// single type, name two types if the instant is ambiguous (DST fall-back), or name none if the
+/// instant does not exist (DST spring-forward gap).
+pub(crate) enum LocalTypes {
+    Single(usize),
+    Ambiguous(usize, usize),
+    None,
+}
+
+impl TimeZoneData {
+    /// Read and parse the TZif file for an IANA zone name, e.g. `"Europe/Paris"`, from the
+    /// system zoneinfo database.
+    pub(crate) fn from_name(name: &str) -> Result<TimeZoneData, Error> {
+        let path = zoneinfo_path(name)?;
+        let data = fs::read(&path).map_err(|e| Error::TzDataInvalid(TzError::new(e)))?;
+        Self::parse(&data)
+    }
+
+    /// Parse a TZif-format buffer.
+    pub(crate) fn parse(data: &[u8]) -> Result<TimeZoneData, Error> {
+        let (header, body) = read_block(data, 4)?;
+        if header.version == 1 {
+            return Ok(TimeZoneData {
+                transitions: body.transitions,
+                local_time_types: body.local_time_types,
+            });
+        }
+
+        // A v2+ file repeats the same data as a 64-bit block right after the v1 block; prefer
+        // that one since it isn't limited to 32-bit transition times.
+        let v1_len = HEADER_LEN + header.data_len(4);
+        let v2_block = data.get(v1_len..).ok_or_else(|| {
+            Error::TzDataInvalid(TzError::new(TzifError("file truncated before the 64-bit data block")))
+        })?;
+        let (_, body64) = read_block(v2_block, 8)?;
+        Ok(TimeZoneData { transitions: body64.transitions, local_time_types: body64.local_time_types })
+    }
+
+    /// The local time type in effect at the given UTC unix timestamp.
+    pub(crate) fn local_time_type(&self, utc_timestamp: i64) -> &LocalTimeType {
+        let idx = match self.transitions.binary_search_by_key(&utc_timestamp, |&(t, _)| t) {
+            Ok(i) => self.transitions[i].1,
+            Err(0) => self.first_local_time_type_index(),
+            Err(i) => self.transitions[i - 1].1,
+        };
+        &self.local_time_types[idx]
+    }
+
+    /// Resolve a *local* (naive, offset-less) timestamp against the transition table.
+    ///
+    /// This first locates the transition boundary as if `local_timestamp` were itself a UTC
+    /// instant, then checks the two neighbouring local time types' actual offsets to decide
+    /// whether the instant is unambiguous, falls in an overlap (ambiguous), or falls in a gap
+    /// (nonexistent).
+    pub(crate) fn find_local(&self, local_timestamp: i64) -> LocalTypes {
+        if self.transitions.is_empty() {
+            return LocalTypes::Single(self.first_local_time_type_index());
+        }
+
+        let mut pos = self.transitions.partition_point(|&(t, _)| t <= local_timestamp);
+        // West of UTC a transition's gap or overlap starts before its UTC instant, so the next
+        // transition may already apply to `local_timestamp`.
+        if let Some(&(next_utc, next_type)) = self.transitions.get(pos) {
+            let prev_type = if pos == 0 { self.first_local_time_type_index() } else { self.transitions[pos - 1].1 };
+            let prev_offset = self.local_time_types[prev_type].utc_offset as i64;
+            let next_offset = self.local_time_types[next_type].utc_offset as i64;
+            if local_timestamp >= next_utc + prev_offset.min(next_offset) {
+                pos += 1;
+            }
+        }
+        if pos == 0 {
+            return LocalTypes::Single(self.first_local_time_type_index());
+        }
+
+        let before = if pos == 1 { self.first_local_time_type_index() } else { self.transitions[pos - 2].1 };
+        let after = self.transitions[pos - 1].1;
+        let transition_utc = self.transitions[pos - 1].0;
+        let before_offset = self.local_time_types[before].utc_offset as i64;
+        let after_offset = self.local_time_types[after].utc_offset as i64;
+        let gap_start = transition_utc + before_offset.min(after_offset);
+        let gap_end = transition_utc + before_offset.max(after_offset);
+
+        if local_timestamp < gap_start {
+            LocalTypes::Single(before)
+        } else if local_timestamp >= gap_end {
+            LocalTypes::Single(after)
+        } else if after_offset > before_offset {
+            LocalTypes::None
+        } else {
+            LocalTypes::Ambiguous(before, after)
+        }
+    }
+
+    pub(crate) fn local_time_types(&self) -> &[LocalTimeType] {
+        &self.local_time_types
+    }
+
+    fn first_local_time_type_index(&self) -> usize {
+        self.local_time_types.iter().position(|t| !t.is_dst).unwrap_or(0)
+    }
+}
+
+fn zoneinfo_path(name: &str) -> Result<PathBuf, Error> {
+    // Reject anything that could escape the zoneinfo directory; valid IANA names are always
+    // relative, e.g. "Europe/Paris" or "UTC".
+    if name.is_empty() || name.starts_with('/') || name.split('/').any(|part| part == "..") {
+        return Err(Error::TzStringInvalid(TzError::new(TzifError("zone name escapes the zoneinfo directory"))));
+    }
+    Ok(Path::new(ZONEINFO_DIR).join(name))
+}
+
+const HEADER_LEN: usize = 44;
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// Length in bytes of the data block that follows this header, given the transition time
+    /// word size (4 bytes for the v1 block, 8 for the v2+ block).
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct Body {
+    transitions: Vec<(i64, usize)>,
+    local_time_types: Vec<LocalTimeType>,
+}
+
+fn read_block(data: &[u8], time_size: usize) -> Result<(Header, Body), Error> {
+    if data.len() < HEADER_LEN || &data[0..4] != TZIF_MAGIC {
+        return Err(Error::TzDataInvalid(TzError::new(TzifError("missing TZif magic or truncated header"))));
+    }
+    let version = match data[4] {
+        0 => 1,
+        b'2' => 2,
+        b'3' => 3,
+        _ => return Err(Error::TzDataInvalid(TzError::new(TzifError("unsupported TZif version")))),
+    };
+    let isutcnt = read_u32(data, 20)? as usize;
+    let isstdcnt = read_u32(data, 24)? as usize;
+    let leapcnt = read_u32(data, 28)? as usize;
+    let timecnt = read_u32(data, 32)? as usize;
+    let typecnt = read_u32(data, 36)? as usize;
+    let charcnt = read_u32(data, 40)? as usize;
+    let header = Header { version, isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt };
+
+    // `timecnt`/`typecnt` come straight from the untrusted header; validate them against the
+    // buffer's actual remaining length before trusting them as `Vec::with_capacity` sizes, or a
+    // crafted header could trigger a multi-gigabyte allocation attempt before any later
+    // per-element bounds check gets a chance to return a graceful `Err`.
+    let remaining = data.len() - HEADER_LEN;
+    let min_len = header
+        .timecnt
+        .checked_mul(time_size + 1)
+        .and_then(|v| v.checked_add(header.typecnt.checked_mul(6)?))
+        .ok_or_else(|| Error::TzDataInvalid(TzError::new(TzifError("timecnt/typecnt too large"))))?;
+    if min_len > remaining {
+        return Err(Error::TzDataInvalid(TzError::new(TzifError("timecnt/typecnt exceed the data available"))));
+    }
+
+    let mut pos = HEADER_LEN;
+    let mut transition_times = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        let t = if time_size == 4 { read_i32(data, pos)? as i64 } else { read_i64(data, pos)? };
+        transition_times.push(t);
+        pos += time_size;
+    }
+    let mut transition_types = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        let type_index = *data
+            .get(pos)
+            .ok_or_else(|| Error::TzDataInvalid(TzError::new(TzifError("truncated transition types"))))?
+            as usize;
+        if type_index >= header.typecnt {
+            return Err(Error::TzDataInvalid(TzError::new(TzifError("transition type index out of range"))));
+        }
+        transition_types.push(type_index);
+        pos += 1;
+    }
+
+    let mut local_time_types = Vec::with_capacity(header.typecnt);
+    for _ in 0..header.typecnt {
+        let utc_offset = read_i32(data, pos)?;
+        // `FixedOffset` rejects exactly ±86,400 as well as anything beyond it, so the valid
+        // range is open on both ends.
+        if !(-86_400 < utc_offset && utc_offset < 86_400) {
+            return Err(Error::TzDataInvalid(TzError::new(TzifError(
+                "UTC offset out of FixedOffset's representable range",
+            ))));
+        }
+        let is_dst = *data
+            .get(pos + 4)
+            .ok_or_else(|| Error::TzDataInvalid(TzError::new(TzifError("truncated local time type record"))))?
+            != 0;
+        local_time_types.push(LocalTimeType { utc_offset, is_dst });
+        pos += 6;
+    }
+    // Abbreviation strings, leap second records and std/wall and ut/local indicators aren't
+    // needed to compute offsets, but they must still be skipped to find the end of this block.
+    pos += header.charcnt + header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt;
+    let _ = pos;
+
+    let transitions = transition_times.into_iter().zip(transition_types).collect();
+    Ok((header, Body { transitions, local_time_types }))
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32, Error> {
+    let bytes: [u8; 4] = data
+        .get(pos..pos + 4)
+        .ok_or_else(|| Error::TzDataInvalid(TzError::new(TzifError("truncated 32-bit field"))))?
+        .try_into()
+        .unwrap();
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn read_i32(data: &[u8], pos: usize) -> Result<i32, Error> {
+    read_u32(data, pos).map(|v| v as i32)
+}
+
+fn read_i64(data: &[u8], pos: usize) -> Result<i64, Error> {
+    let bytes: [u8; 8] = data
+        .get(pos..pos + 8)
+        .ok_or_else(|| Error::TzDataInvalid(TzError::new(TzifError("truncated 64-bit field"))))?
+        .try_into()
+        .unwrap();
+    Ok(i64::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalTypes, TimeZoneData};
+    use crate::Error;
+
+    /// Build a single TZif data block (header + body, no trailing POSIX TZ string), with
+    /// transition times encoded at `time_size` bytes (4 for a v1 block, 8 for a v2+ block).
+    fn build_block(version: u8, time_size: usize, transitions: &[(i64, u8)], types: &[(i32, bool)]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"TZif");
+        buf.push(version);
+        buf.extend_from_slice(&[0u8; 15]); // reserved
+        buf.extend_from_slice(&0u32.to_be_bytes()); // isutcnt
+        buf.extend_from_slice(&0u32.to_be_bytes()); // isstdcnt
+        buf.extend_from_slice(&0u32.to_be_bytes()); // leapcnt
+        buf.extend_from_slice(&(transitions.len() as u32).to_be_bytes()); // timecnt
+        buf.extend_from_slice(&(types.len() as u32).to_be_bytes()); // typecnt
+        buf.extend_from_slice(&0u32.to_be_bytes()); // charcnt
+
+        for &(t, _) in transitions {
+            if time_size == 4 {
+                buf.extend_from_slice(&(t as i32).to_be_bytes());
+            } else {
+                buf.extend_from_slice(&t.to_be_bytes());
+            }
+        }
+        for &(_, ty) in transitions {
+            buf.push(ty);
+        }
+        for &(offset, is_dst) in types {
+            buf.extend_from_slice(&offset.to_be_bytes());
+            buf.push(is_dst as u8);
+            buf.push(0); // abbreviation index, unused
+        }
+        buf
+    }
+
+    fn build_v1(transitions: &[(i64, u8)], types: &[(i32, bool)]) -> Vec<u8> {
+        build_block(0, 4, transitions, types)
+    }
+
+    #[test]
+    fn spring_forward_gap_is_unresolvable() {
+        // 0 -> STD (UTC+0), 1 -> DST (UTC+1h), transitioning forward at t=1000.
+        let data = build_v1(&[(1000, 1)], &[(0, false), (3600, true)]);
+        let tz = TimeZoneData::parse(&data).unwrap();
+
+        assert!(matches!(tz.find_local(500), LocalTypes::Single(0)));
+        assert!(matches!(tz.find_local(1000), LocalTypes::None));
+        assert!(matches!(tz.find_local(4599), LocalTypes::None));
+        assert!(matches!(tz.find_local(4600), LocalTypes::Single(1)));
+        assert!(matches!(tz.find_local(5000), LocalTypes::Single(1)));
+    }
+
+    #[test]
+    fn fall_back_overlap_is_ambiguous() {
+        // 0 -> STD (UTC+0), 1 -> DST (UTC+1h): forward at t=0, back again at t=100_000.
+        let data = build_v1(&[(0, 1), (100_000, 0)], &[(0, false), (3600, true)]);
+        let tz = TimeZoneData::parse(&data).unwrap();
+
+        // Steady DST period, well past the initial gap and before the fall-back overlap.
+        assert!(matches!(tz.find_local(50_000), LocalTypes::Single(1)));
+        // Inside the repeated hour.
+        assert!(matches!(tz.find_local(101_000), LocalTypes::Ambiguous(1, 0)));
+        // Past the overlap, back to a clean single STD resolution.
+        assert!(matches!(tz.find_local(104_000), LocalTypes::Single(0)));
+    }
+
+    #[test]
+    fn local_time_type_resolves_by_utc_instant() {
+        let data = build_v1(&[(1000, 1)], &[(0, false), (3600, true)]);
+        let tz = TimeZoneData::parse(&data).unwrap();
+
+        assert_eq!(tz.local_time_type(500).utc_offset, 0);
+        assert_eq!(tz.local_time_type(1000).utc_offset, 3600);
+        assert_eq!(tz.local_time_type(5000).utc_offset, 3600);
+    }
+
+    #[test]
+    fn rejects_truncated_or_malformed_header() {
+        assert!(matches!(TimeZoneData::parse(b"too short"), Err(Error::TzDataInvalid(_))));
+        assert!(matches!(TimeZoneData::parse(&[0u8; 44]), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn rejects_out_of_range_transition_type() {
+        // Only one local time type (index 0) is defined, but the transition points at index 1.
+        let data = build_v1(&[(1000, 1)], &[(0, false)]);
+        assert!(matches!(TimeZoneData::parse(&data), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn rejects_huge_timecnt_without_allocating() {
+        // Header claims far more transitions than the (empty) body could possibly hold.
+        let mut data = build_v1(&[], &[(0, false)]);
+        data[32..36].copy_from_slice(&0xFFFF_FFFFu32.to_be_bytes());
+        assert!(matches!(TimeZoneData::parse(&data), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn rejects_offset_outside_fixed_offset_range() {
+        // A UTC offset of +25h can't be represented by `FixedOffset`.
+        let data = build_v1(&[], &[(90_000, false)]);
+        assert!(matches!(TimeZoneData::parse(&data), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn rejects_offset_exactly_at_the_boundary() {
+        // The valid range is open on both ends: exactly +-86,400 is rejected too, not just
+        // values beyond it.
+        let plus = build_v1(&[], &[(86_400, false)]);
+        assert!(matches!(TimeZoneData::parse(&plus), Err(Error::TzDataInvalid(_))));
+        let minus = build_v1(&[], &[(-86_400, false)]);
+        assert!(matches!(TimeZoneData::parse(&minus), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn rejects_truncated_v2_block() {
+        // Claims to be a v2 file but is cut off right after the v1 block ends.
+        let mut data = build_v1(&[(1000, 1)], &[(0, false), (3600, true)]);
+        data[4] = b'2';
+        assert!(matches!(TimeZoneData::parse(&data), Err(Error::TzDataInvalid(_))));
+    }
+
+    #[test]
+    fn prefers_the_64_bit_v2_block_when_present() {
+        let v1 = build_v1(&[(1000, 1)], &[(0, false), (3600, true)]);
+
+        // The 64-bit block disagrees with the 32-bit one (fall back transition instead of
+        // forward), so parsing must be picking up the v2 data, not the v1 data.
+        let mut v2 = build_block(b'2', 8, &[(0, 1), (100_000, 0)], &[(0, false), (3600, true)]);
+        let mut data = v1;
+        data[4] = b'2';
+        data.append(&mut v2);
+
+        let tz = TimeZoneData::parse(&data).unwrap();
+        assert!(matches!(tz.find_local(101_000), LocalTypes::Ambiguous(1, 0)));
+    }
+}
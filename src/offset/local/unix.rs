@@ -0,0 +1,62 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Local time zone lookup for unix-like systems, backed by the system zoneinfo database.
+
+use std::fs;
+use std::sync::OnceLock;
+
+use super::tz_info::{LocalTypes, TimeZoneData};
+use crate::error::TzError;
+use crate::offset::{FixedOffset, LocalResult};
+use crate::{DateTime, Error, Local, NaiveDateTime, TimeDelta};
+
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+/// Read and cache the system's local zoneinfo data from `/etc/localtime`.
+///
+/// `/etc/localtime` is conventionally a symlink into the zoneinfo database (or a copy of the
+/// relevant TZif file). Failing to read it at all is a platform-API failure distinct from the
+/// file being present but malformed, so it's reported as [`Error::PlatformError`] rather than
+/// [`Error::TzDataInvalid`] (which [`TimeZoneData::parse`] already covers).
+fn local_zone() -> Result<&'static TimeZoneData, Error> {
+    static ZONE: OnceLock<Result<TimeZoneData, Error>> = OnceLock::new();
+    ZONE.get_or_init(|| {
+        let data = fs::read(LOCALTIME_PATH).map_err(|e| Error::PlatformError(TzError::new(e)))?;
+        TimeZoneData::parse(&data)
+    })
+    .as_ref()
+    .map_err(Clone::clone)
+}
+
+pub(super) fn now() -> Result<DateTime<Local>, Error> {
+    let utc = crate::Utc::now()?.naive_utc();
+    naive_to_local(&utc, false)?.single()
+}
+
+/// Resolve a naive datetime against the system's local zone.
+///
+/// When `local` is `true`, `d` is treated as a local (offset-less) timestamp and may resolve to
+/// zero, one, or two results (DST gap or fall-back overlap). When `false`, `d` is treated as a
+/// UTC instant, which is never ambiguous.
+pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> Result<LocalResult<DateTime<Local>>, Error> {
+    let zone = local_zone()?;
+    let timestamp = d.and_utc().timestamp();
+
+    if !local {
+        let offset = FixedOffset::east(zone.local_time_type(timestamp).utc_offset)?;
+        return Ok(LocalResult::Single(DateTime::from_utc(*d, offset)));
+    }
+
+    let to_datetime = |idx: usize| -> Result<DateTime<Local>, Error> {
+        let offset = FixedOffset::east(zone.local_time_types()[idx].utc_offset)?;
+        let naive_utc = *d - TimeDelta::seconds(offset.local_minus_utc() as i64);
+        Ok(DateTime::from_utc(naive_utc, offset))
+    };
+
+    Ok(match zone.find_local(timestamp) {
+        LocalTypes::Single(idx) => LocalResult::Single(to_datetime(idx)?),
+        LocalTypes::Ambiguous(a, b) => LocalResult::Ambiguous(to_datetime(a)?, to_datetime(b)?),
+        LocalTypes::None => LocalResult::None,
+    })
+}
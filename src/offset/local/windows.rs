@@ -0,0 +1,65 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Local time zone lookup for Windows, backed by the `GetTimeZoneInformation` API.
+
+use crate::offset::{FixedOffset, LocalResult};
+use crate::{DateTime, Error, Local, NaiveDateTime, TimeDelta};
+
+#[repr(C)]
+#[allow(non_snake_case)]
+struct TimeZoneInformation {
+    Bias: i32,
+    StandardName: [u16; 32],
+    StandardDate: [u16; 8],
+    StandardBias: i32,
+    DaylightName: [u16; 32],
+    DaylightDate: [u16; 8],
+    DaylightBias: i32,
+}
+
+const TIME_ZONE_ID_INVALID: u32 = 0xFFFFFFFF;
+const TIME_ZONE_ID_DAYLIGHT: u32 = 2;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetTimeZoneInformation(information: *mut TimeZoneInformation) -> u32;
+}
+
+/// Ask the OS for the system's current bias from UTC, in whole minutes west of UTC, accounting
+/// for whether daylight saving time is currently in effect.
+///
+/// `GetTimeZoneInformation` reports `TIME_ZONE_ID_INVALID` on failure; that's a genuine
+/// platform-API failure (as opposed to a malformed on-disk TZif file, which doesn't apply on
+/// this target), so it's reported as [`Error::PlatformError`].
+fn bias_minutes() -> Result<i32, Error> {
+    // Safety: `info` is a plain-old-data struct with no invariants beyond its bit pattern, and
+    // `GetTimeZoneInformation` only ever writes to it, never reads from it before initializing.
+    let mut info: TimeZoneInformation = unsafe { std::mem::zeroed() };
+    let result = unsafe { GetTimeZoneInformation(&mut info) };
+    match result {
+        TIME_ZONE_ID_INVALID => {
+            Err(Error::PlatformError(crate::error::TzError::new(std::io::Error::last_os_error())))
+        }
+        TIME_ZONE_ID_DAYLIGHT => Ok(info.Bias + info.DaylightBias),
+        // TIME_ZONE_ID_STANDARD and TIME_ZONE_ID_UNKNOWN (no DST defined for this zone) both
+        // use the standard-time bias.
+        _ => Ok(info.Bias + info.StandardBias),
+    }
+}
+
+pub(super) fn now() -> Result<DateTime<Local>, Error> {
+    let utc = crate::Utc::now()?.naive_utc();
+    naive_to_local(&utc, false)?.single()
+}
+
+pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> Result<LocalResult<DateTime<Local>>, Error> {
+    let offset = FixedOffset::west(bias_minutes()? * 60)?;
+
+    if !local {
+        return Ok(LocalResult::Single(DateTime::from_utc(*d, offset)));
+    }
+
+    let naive_utc = *d - TimeDelta::seconds(offset.local_minus_utc() as i64);
+    Ok(LocalResult::Single(DateTime::from_utc(naive_utc, offset)))
+}
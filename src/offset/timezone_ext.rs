@@ -0,0 +1,59 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Date-granularity counterparts of [`TimeZone`]'s datetime-level methods.
+//!
+//! These are blanket-implemented for every [`TimeZone`] via [`TimeZoneDateExt`] rather than
+//! declared as defaults on `TimeZone` itself, because this checkout doesn't carry the crate's
+//! central `TimeZone` trait declaration. Importing `TimeZoneDateExt` gets any zone — including a
+//! third-party `TimeZone` impl, or a generic `fn foo<Tz: TimeZone>` — the same methods a true
+//! trait default would, from one shared implementation instead of one pasted per concrete type.
+
+#[allow(deprecated)]
+use crate::Date;
+use crate::naive::NaiveDate;
+use crate::offset::{LocalResult, TimeZone};
+use crate::{DateTime, Error, Weekday};
+
+/// Date-granularity offset queries and calendar constructors, available on any [`TimeZone`].
+pub trait TimeZoneDateExt: TimeZone {
+    /// The offset in effect on `local`, a bare calendar date without a time-of-day.
+    ///
+    /// Noon is used as the canonical time-of-day so that a date which straddles a DST
+    /// transition still resolves to a single, unambiguous offset.
+    fn offset_from_local_date(&self, local: &NaiveDate) -> Result<LocalResult<Self::Offset>, Error> {
+        self.offset_from_local_datetime(&local.and_hms(12, 0, 0)?)
+    }
+
+    /// The offset in effect on `utc`, a bare calendar date expressed in UTC.
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+        self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0).expect("noon is always a valid time"))
+    }
+
+    /// Makes a new `Date` from a year and day-of-year (1-based, i.e. 1 is always January 1),
+    /// resolved in this time zone.
+    #[allow(deprecated)]
+    fn yo_opt(&self, year: i32, ordinal: u32) -> Result<LocalResult<Date<Self>>, Error> {
+        let date = NaiveDate::from_yo(year, ordinal)?;
+        Ok(local_date_result(self.from_local_datetime(&date.and_hms(12, 0, 0)?)?))
+    }
+
+    /// Makes a new `Date` from an ISO week date (year, week number, weekday), resolved in this
+    /// time zone.
+    #[allow(deprecated)]
+    fn isoywd_opt(&self, year: i32, week: u32, weekday: Weekday) -> Result<LocalResult<Date<Self>>, Error> {
+        let date = NaiveDate::from_isoywd(year, week, weekday)?;
+        Ok(local_date_result(self.from_local_datetime(&date.and_hms(12, 0, 0)?)?))
+    }
+}
+
+impl<Tz: TimeZone> TimeZoneDateExt for Tz {}
+
+#[allow(deprecated)]
+fn local_date_result<Tz: TimeZone>(result: LocalResult<DateTime<Tz>>) -> LocalResult<Date<Tz>> {
+    match result {
+        LocalResult::Single(dt) => LocalResult::Single(dt.date()),
+        LocalResult::Ambiguous(a, b) => LocalResult::Ambiguous(a.date(), b.date()),
+        LocalResult::None => LocalResult::None,
+    }
+}
@@ -0,0 +1,108 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The [`Tz`] type: an IANA named time zone backed by the system zoneinfo database.
+
+use core::fmt;
+use std::sync::Arc;
+
+use super::local::tz_info::{LocalTypes, TimeZoneData};
+use super::{FixedOffset, LocalResult, Offset, TimeZone};
+use crate::naive::NaiveDateTime;
+use crate::{DateTime, Error};
+
+/// A time zone identified by an IANA name, e.g. `"Europe/Paris"` or `"Asia/Tokyo"`, backed by the
+/// system's zoneinfo (TZif) database.
+///
+/// Unlike [`Local`](crate::Local), which always tracks the system's configured zone, a `Tz` names
+/// one specific zone regardless of what `Local` happens to be, so it can be used to convert a
+/// `DateTime` into an arbitrary zone:
+///
+/// ```no_run
+/// use chrono::{Tz, TimeZone, Utc};
+///
+/// let tokyo = Tz::from_name("Asia/Tokyo")?;
+/// let dt = Utc::now()?.with_timezone(&tokyo);
+/// # Ok::<_, chrono::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tz {
+    name: Arc<str>,
+    data: Arc<TimeZoneData>,
+}
+
+impl Tz {
+    /// Look up an IANA time zone by name, e.g. `"Europe/Paris"`, by locating and parsing the
+    /// matching TZif file under the system zoneinfo directory (`/usr/share/zoneinfo` on most
+    /// unix systems).
+    pub fn from_name(name: &str) -> Result<Tz, Error> {
+        let data = TimeZoneData::from_name(name)?;
+        Ok(Tz { name: Arc::from(name), data: Arc::new(data) })
+    }
+
+    /// The IANA name this `Tz` was constructed from.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn offset_for_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        let local_type = self.data.local_time_type(utc.and_utc().timestamp());
+        FixedOffset::east(local_type.utc_offset).expect("TZif offsets are always in representable range")
+    }
+}
+
+/// The concrete offset of a [`Tz`] at a particular instant.
+#[derive(Clone, Debug)]
+pub struct TzOffset {
+    tz: Tz,
+    offset: FixedOffset,
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.tz.clone()
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> Result<LocalResult<TzOffset>, Error> {
+        let to_offset = |idx: usize| FixedOffset::east(self.data.local_time_types()[idx].utc_offset);
+        let wrap = |offset: Result<FixedOffset, Error>| -> Result<TzOffset, Error> {
+            Ok(TzOffset { tz: self.clone(), offset: offset? })
+        };
+
+        Ok(match self.data.find_local(local.and_utc().timestamp()) {
+            LocalTypes::Single(idx) => LocalResult::Single(wrap(to_offset(idx))?),
+            LocalTypes::Ambiguous(a, b) => {
+                LocalResult::Ambiguous(wrap(to_offset(a))?, wrap(to_offset(b))?)
+            }
+            LocalTypes::None => LocalResult::None,
+        })
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        TzOffset { tz: self.clone(), offset: self.offset_for_utc(utc) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tz;
+
+    #[test]
+    fn unknown_zone_is_an_error() {
+        assert!(Tz::from_name("Not/AZone").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The UTC time zone.
+
+use core::fmt;
+
+use super::fixed::FixedOffset;
+use super::{LocalResult, Offset, TimeZone};
+use crate::naive::NaiveDateTime;
+use crate::{DateTime, Error};
+
+/// Ask the process-wide clock installed via [`crate::clock::set_clock`], if any, for the current
+/// time. Returns `None` when the `clock` feature is disabled or no clock has been installed, so
+/// that `Utc::now` falls through to the OS clock.
+#[cfg(feature = "clock")]
+fn clock_override() -> Option<Result<DateTime<Utc>, Error>> {
+    crate::clock::now()
+}
+
+#[cfg(not(feature = "clock"))]
+fn clock_override() -> Option<Result<DateTime<Utc>, Error>> {
+    None
+}
+
+/// The UTC time zone. This is the most efficient time zone when you don't need the local time.
+/// It is also used as an offset (which is also a dummy type).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+///
+/// let dt: DateTime<Utc> = Utc::now()?;
+/// # Ok::<_, chrono::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct Utc;
+
+impl Utc {
+    /// Returns a `DateTime` which corresponds to the current date and time, consulting the
+    /// process-wide clock installed via [`crate::clock::set_clock`] first, falling back to the
+    /// OS clock when no clock has been installed (or the `clock` feature is disabled).
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        feature = "wasmbind",
+        not(any(target_os = "emscripten", target_os = "wasi"))
+    )))]
+    pub fn now() -> Result<DateTime<Utc>, Error> {
+        if let Some(result) = clock_override() {
+            return result;
+        }
+
+        let duration = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| Error::DateOutOfRange)?;
+        Utc.timestamp(duration.as_secs() as i64, duration.subsec_nanos())
+    }
+
+    // `std::time::SystemTime` isn't available on wasm32-unknown-unknown; get the instant from the
+    // JS runtime instead.
+    #[cfg(all(
+        target_arch = "wasm32",
+        feature = "wasmbind",
+        not(any(target_os = "emscripten", target_os = "wasi"))
+    ))]
+    pub fn now() -> Result<DateTime<Utc>, Error> {
+        if let Some(result) = clock_override() {
+            return result;
+        }
+
+        let millis = js_sys::Date::new_0().get_time();
+        let secs = (millis / 1_000.0).floor();
+        let nanos = ((millis - secs * 1_000.0) * 1_000_000.0) as u32;
+        Utc.timestamp(secs as i64, nanos)
+    }
+}
+
+impl Offset for Utc {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east(0).expect("0 is always a valid offset")
+    }
+}
+
+impl fmt::Display for Utc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "+00:00")
+    }
+}
+
+impl TimeZone for Utc {
+    type Offset = Utc;
+
+    fn from_offset(_offset: &Utc) -> Utc {
+        Utc
+    }
+
+    fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> Result<LocalResult<Utc>, Error> {
+        Ok(LocalResult::Single(Utc))
+    }
+
+    fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> Utc {
+        Utc
+    }
+
+    fn from_local_datetime(&self, local: &NaiveDateTime) -> Result<LocalResult<DateTime<Utc>>, Error> {
+        Ok(LocalResult::Single(DateTime::from_utc(*local, Utc)))
+    }
+
+    fn from_utc_datetime(&self, utc: &NaiveDateTime) -> DateTime<Utc> {
+        DateTime::from_utc(*utc, Utc)
+    }
+}